@@ -0,0 +1,84 @@
+mod mysql;
+mod postgres;
+mod sqlite;
+
+pub use mysql::MySqlInfoProvider;
+pub use postgres::PostgresInfoProvider;
+pub use sqlite::SqliteInfoProvider;
+
+use crate::generator::runner::Framework;
+use crate::rust::Type;
+use anyhow::Error;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Enum {
+    pub name: String,
+    pub values: Vec<EnumValue>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompositeType {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub name: String,
+    pub udt_name: String,
+    pub is_nullable: bool,
+    pub is_primary_key: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    pub enumerations: Vec<Enum>,
+    pub composite_types: Vec<CompositeType>,
+    pub tables: Vec<Table>,
+}
+
+/// A backend-specific source of schema information and type mappings.
+///
+/// Each SQL backend (Postgres, MySQL, SQLite, ...) implements this trait so
+/// `Generator` stays backend-agnostic: it asks the provider for the schema,
+/// for how a backend-reported type name maps onto a [`Type`], and for which
+/// `use` paths that [`Type`] needs under a given [`Framework`].
+#[async_trait]
+pub trait InfoProvider: Send + Sync {
+    /// Fetches and parses the full schema (enums, composites, tables) from the backend.
+    async fn get_schema(&self) -> Result<Schema, Error>;
+
+    /// Maps a backend-reported type name (e.g. a Postgres `udt_name` or a
+    /// MySQL column type) onto its Rust equivalent.
+    fn type_name_from(&self, type_name: &str) -> Type;
+
+    /// Returns the `use` paths required to reference `rust_type` under `framework`.
+    fn imports_for(&self, rust_type: &Type, framework: Framework) -> Vec<String>;
+
+    /// Whether `Options::queries`' generated CRUD modules can run against this
+    /// backend. They're built on [`crate::generator::GenericClient`], which is
+    /// bound to `sqlx::Executor<Database = Postgres>`, and their query text
+    /// uses Postgres' `$1`-style placeholders, so only the Postgres provider
+    /// supports them today.
+    fn supports_generic_queries(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Column, InfoProvider, Schema, Table};
+use crate::generator::runner::Framework;
+use crate::rust::Type;
+use anyhow::Error;
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+
+pub struct SqliteInfoProvider {
+    pool: SqlitePool,
+}
+
+impl SqliteInfoProvider {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InfoProvider for SqliteInfoProvider {
+    async fn get_schema(&self) -> Result<Schema, Error> {
+        let table_names: Vec<String> = sqlx::query(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| row.get("name"))
+        .collect();
+
+        let mut tables: Vec<Table> = vec![];
+        for table_name in table_names {
+            let columns = sqlx::query(&format!("PRAGMA table_info({table_name})"))
+                .fetch_all(&self.pool)
+                .await?
+                .iter()
+                .map(|row| {
+                    let pk: i64 = row.get("pk");
+                    Column {
+                        name: row.get("name"),
+                        udt_name: row.get("type"),
+                        // SQLite's dynamic typing means any column, `NOT NULL` or
+                        // not, can still surface a `NULL` through type coercion
+                        // and untyped inserts, so every column is generated as
+                        // optional rather than trusting the declared constraint.
+                        is_nullable: true,
+                        is_primary_key: pk > 0,
+                    }
+                })
+                .collect();
+
+            tables.push(Table {
+                name: table_name,
+                columns,
+            });
+        }
+
+        // SQLite has no enum or composite type catalog; every column is one
+        // of its five storage classes, handled entirely by `type_name_from`.
+        Ok(Schema {
+            enumerations: vec![],
+            composite_types: vec![],
+            tables,
+        })
+    }
+
+    fn type_name_from(&self, type_name: &str) -> Type {
+        let affinity = type_name.to_uppercase();
+        if affinity.contains("INT") {
+            Type::I64
+        } else if affinity.contains("REAL") || affinity.contains("FLOA") || affinity.contains("DOUB") {
+            Type::F64
+        } else if affinity.contains("BLOB") || affinity.is_empty() {
+            Type::Bytes
+        } else {
+            Type::Text
+        }
+    }
+
+    fn imports_for(&self, rust_type: &Type, _framework: Framework) -> Vec<String> {
+        match rust_type {
+            Type::Option(inner) | Type::Vector(inner) => self.imports_for(inner, _framework),
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    // `connect_lazy` only parses the URL; it never opens the database file,
+    // so this is safe to build in a unit test without one on disk.
+    fn provider() -> SqliteInfoProvider {
+        let pool = SqlitePoolOptions::new()
+            .connect_lazy("sqlite::memory:")
+            .expect("valid connection URL");
+        SqliteInfoProvider::new(pool)
+    }
+
+    #[test]
+    fn maps_storage_classes_by_affinity() {
+        let provider = provider();
+        assert_eq!(provider.type_name_from("INTEGER"), Type::I64);
+        assert_eq!(provider.type_name_from("REAL"), Type::F64);
+        assert_eq!(provider.type_name_from("DOUBLE"), Type::F64);
+        assert_eq!(provider.type_name_from("BLOB"), Type::Bytes);
+        assert_eq!(provider.type_name_from(""), Type::Bytes);
+        assert_eq!(provider.type_name_from("TEXT"), Type::Text);
+        assert_eq!(provider.type_name_from("VARCHAR(20)"), Type::Text);
+    }
+
+    #[test]
+    fn supports_generic_queries_is_false() {
+        assert!(!provider().supports_generic_queries());
+    }
+}
@@ -0,0 +1,17 @@
+use sqlx::{Executor, Postgres};
+
+/// A connection-agnostic bound for the generated query functions.
+///
+/// Blanket-implemented for anything sqlx already knows how to run a query
+/// against (`&PgPool`, `&mut PgConnection`, a transaction, ...), so a pooled
+/// `deadpool` connection works here too as long as it derefs to one of
+/// those. Generated functions take `&impl GenericClient` instead of a
+/// concrete connection type so callers aren't forced onto a single one.
+///
+/// Bound to `Database = Postgres`: the generated query text uses Postgres'
+/// `$1`-style placeholders, so this only works against a Postgres connection.
+/// `Generator::generate_code` refuses `Options::queries` against any
+/// `InfoProvider` that doesn't report `supports_generic_queries()`.
+pub trait GenericClient: for<'c> Executor<'c, Database = Postgres> + Send + Sync {}
+
+impl<T> GenericClient for T where T: for<'c> Executor<'c, Database = Postgres> + Send + Sync {}
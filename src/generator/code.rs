@@ -1,7 +1,7 @@
 use crate::database::InfoProvider;
 use crate::rust;
 use crate::{database, rust::Type};
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use cruet::Inflector;
 use std::collections::HashSet;
 
@@ -13,11 +13,13 @@ Contains fields that indicate formatting options that should be applied to the g
 # Fields
 - `singular`: specifies with the generated Rust structs name should be the singular form the provided tables
 - `framework`: specifies the framework to be used for generating the code
+- `queries`: specifies whether a typed CRUD query module should be generated alongside each table's struct
 */
 #[derive(Debug)]
 pub struct Options {
     pub singular: bool,
     pub framework: Framework,
+    pub queries: bool,
 }
 
 impl Default for Options {
@@ -25,6 +27,7 @@ impl Default for Options {
         Self {
             singular: false,
             framework: Framework::None,
+            queries: false,
         }
     }
 }
@@ -45,6 +48,24 @@ impl Generator {
         snippets.append(&mut self.code_from_enums(&schema.enumerations));
         snippets.append(&mut self.code_from_composites(&schema.composite_types));
         snippets.append(&mut self.code_from_tables(&schema.tables));
+        if self.options.queries {
+            if !self.provider.supports_generic_queries() {
+                return Err(anyhow!(
+                    "Options::queries is only supported for backends whose InfoProvider reports \
+                     supports_generic_queries() (currently Postgres only)"
+                ));
+            }
+            // The CRUD bodies decode rows with `sqlx::query_as::<_, Struct>`,
+            // which needs `Struct: sqlx::FromRow` — only granted by
+            // `add_framework_macros` under `Framework::Sqlx`.
+            if self.options.framework != Framework::Sqlx {
+                return Err(anyhow!(
+                    "Options::queries requires Framework::Sqlx, since the generated CRUD \
+                     functions decode rows via sqlx::FromRow"
+                ));
+            }
+            snippets.append(&mut self.code_from_table_queries(&schema.tables));
+        }
 
         // Finalize all snippets
         for snippet in &mut snippets {
@@ -54,6 +75,16 @@ impl Generator {
         Ok(snippets)
     }
 
+    /// Reconstructs the schema as `CREATE TYPE`/`CREATE TABLE` statements,
+    /// ordered so dependencies come first, for snapshotting a live database
+    /// into a versioned `.sql` migration. This is the inverse of
+    /// `generate_code`'s struct generation and is surfaced as a separate
+    /// output target rather than folded into the Rust snippets.
+    pub async fn generate_ddl(&self) -> Result<Vec<super::ddl::DdlStatement>, Error> {
+        let schema = self.provider.get_schema().await?;
+        Ok(super::ddl::ddl_from_schema(&schema))
+    }
+
     fn code_from_enums(&self, enums: &[database::Enum]) -> Vec<Snippet> {
         enums
             .iter()
@@ -64,15 +95,34 @@ impl Generator {
                 let macros = match self.options.framework {
                     Framework::None => "#[derive(Debug, Clone, PartialEq, Eq)]\n",
                     Framework::Sqlx => "#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]\n",
+                    Framework::Postgres => {
+                        snippet.add_import("postgres_types::{ToSql, FromSql}");
+                        "#[derive(Debug, Clone, PartialEq, Eq, ToSql, FromSql)]\n"
+                    }
+                    Framework::Diesel => "#[derive(Debug, Clone, PartialEq, Eq)]\n",
                 };
 
                 snippet.code.push_str(macros);
+                if self.options.framework == Framework::Postgres {
+                    snippet
+                        .code
+                        .push_str(&format!("#[postgres(name = \"{}\")]\n", e.name));
+                }
                 snippet.code.push_str(&format!("pub enum {} {{\n", name));
 
                 for value in &e.values {
-                    if self.options.framework == Framework::Sqlx {
-                        let rename_macro = format!("    #[sqlx(rename = \"{}\")]\n", value.name);
-                        snippet.code.push_str(&rename_macro);    
+                    match self.options.framework {
+                        Framework::Sqlx => {
+                            let rename_macro =
+                                format!("    #[sqlx(rename = \"{}\")]\n", value.name);
+                            snippet.code.push_str(&rename_macro);
+                        }
+                        Framework::Postgres => {
+                            let rename_macro =
+                                format!("    #[postgres(name = \"{}\")]\n", value.name);
+                            snippet.code.push_str(&rename_macro);
+                        }
+                        Framework::None | Framework::Diesel => {}
                     }
                     let field_name = value.name.to_pascal_case();
                     let enum_field = format!("    {field_name},\n");
@@ -94,8 +144,18 @@ impl Generator {
                 let macros = match self.options.framework {
                     Framework::None => "#[derive(Debug, Clone)]\n",
                     Framework::Sqlx => "#[derive(Debug, Clone, sqlx::Type)]\n",
+                    Framework::Postgres => {
+                        snippet.add_import("postgres_types::{ToSql, FromSql}");
+                        "#[derive(Debug, Clone, ToSql, FromSql)]\n"
+                    }
+                    Framework::Diesel => "#[derive(Debug, Clone)]\n",
                 };
                 snippet.code.push_str(macros);
+                if self.options.framework == Framework::Postgres {
+                    snippet
+                        .code
+                        .push_str(&format!("#[postgres(name = \"{}\")]\n", composite.name));
+                }
                 snippet
                     .code
                     .push_str(&format!("pub struct {} {{\n", table_name.to_pascal_case()));
@@ -116,75 +176,327 @@ impl Generator {
     }
 
     fn code_from_tables(&self, tables: &[database::Table]) -> Vec<Snippet> {
+        let mut snippets = vec![];
+
+        for table in tables {
+            let table_name = self.format_name(&table.name);
+            let mut snippet = Snippet::new(table_name.clone());
+            self.add_framework_macros(&mut snippet, &table_name);
+
+            snippet
+                .code
+                .push_str(&format!("pub struct {} {{\n", table_name.to_pascal_case()));
+
+            for column in &table.columns {
+                let rust_type = self.column_rust_type(column);
+
+                self.add_type_imports(&mut snippet, &rust_type);
+                let field_name = column.name.clone();
+                self.add_framework_attribute(&rust_type, &mut snippet);
+
+                let struct_field = format!("    pub {field_name}: {rust_type},\n");
+                snippet.code.push_str(&struct_field);
+            }
+
+            snippet.code.push('}');
+            snippets.push(snippet);
+
+            if self.options.framework == Framework::Diesel {
+                snippets.push(self.diesel_table_schema(table, &table_name));
+            }
+        }
+
+        snippets
+    }
+
+    // Emits the `diesel::table!` macro block the generated struct's
+    // `#[diesel(table_name = ...)]` attribute refers to.
+    fn diesel_table_schema(&self, table: &database::Table, table_name: &str) -> Snippet {
+        let mut snippet = Snippet::new(format!("{table_name}_schema"));
+        snippet.add_import("diesel::table");
+
+        let pk = table
+            .columns
+            .iter()
+            .find(|c| c.is_primary_key)
+            .or_else(|| table.columns.first());
+        let pk_name = pk.map(|c| c.name.as_str()).unwrap_or("id");
+
+        // A column backed by a generated enum/composite (`Type::Custom`) has
+        // no built-in `diesel::sql_types` counterpart; Diesel's own pattern
+        // for this is a marker type implementing `#[derive(SqlType)]` in a
+        // `sql_types` module, referenced here by path, so flag that it still
+        // needs to be hand-written for each one this table uses.
+        let custom_types = self.custom_type_names(table);
+        if !custom_types.is_empty() {
+            snippet.code.push_str(&format!(
+                "// Requires a `#[derive(SqlType)]` marker type at crate::sql_types::{{{}}}\n",
+                custom_types.join(", ")
+            ));
+        }
+
+        snippet.code.push_str("table! {\n");
+        // `table!`'s identifier doubles as the literal SQL table name unless
+        // overridden, so when `table_name` has been reformatted (e.g.
+        // singularized) from `table.name`, pin the real one down explicitly.
+        if table_name != table.name {
+            snippet
+                .code
+                .push_str(&format!("    #[sql_name = \"{}\"]\n", table.name));
+        }
+        snippet
+            .code
+            .push_str(&format!("    {table_name} ({pk_name}) {{\n"));
+        for column in &table.columns {
+            let rust_type = self.column_rust_type(column);
+            let sql_type = Self::diesel_sql_type(&rust_type);
+            snippet
+                .code
+                .push_str(&format!("        {} -> {sql_type},\n", column.name));
+        }
+        snippet.code.push_str("    }\n}\n");
+
+        snippet
+    }
+
+    // Names of generated enum/composite types (`Type::Custom`) referenced by
+    // `table`'s columns, in column order with duplicates removed.
+    fn custom_type_names(&self, table: &database::Table) -> Vec<String> {
+        fn custom_name(rust_type: &Type) -> Option<&str> {
+            match rust_type {
+                Type::Custom(name) => Some(name),
+                Type::Option(inner) | Type::Vector(inner) => custom_name(inner),
+                _ => None,
+            }
+        }
+
+        let mut names = vec![];
+        for column in &table.columns {
+            let rust_type = self.column_rust_type(column);
+            if let Some(name) = custom_name(&rust_type) {
+                if !names.iter().any(|n: &String| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+
+    // Maps a `Type` onto the `diesel::sql_types` name used inside a `table!` block.
+    fn diesel_sql_type(rust_type: &Type) -> String {
+        match rust_type {
+            Type::Bool => "Bool".to_string(),
+            Type::I16 => "SmallInt".to_string(),
+            Type::I32 => "Integer".to_string(),
+            Type::I64 => "BigInt".to_string(),
+            Type::F32 => "Float".to_string(),
+            Type::F64 => "Double".to_string(),
+            Type::Text => "Text".to_string(),
+            Type::Bytes => "Binary".to_string(),
+            Type::Uuid(_) => "Uuid".to_string(),
+            Type::Date(_) => "Date".to_string(),
+            Type::Time(_) => "Time".to_string(),
+            Type::Timestamp(_) => "Timestamp".to_string(),
+            Type::TimestampWithTz(_) => "Timestamptz".to_string(),
+            Type::Decimal(_) => "Numeric".to_string(),
+            Type::Json(_) => "Jsonb".to_string(),
+            // Not a built-in `diesel::sql_types` name; reference the marker
+            // type that the comment emitted by `diesel_table_schema` asks the
+            // caller to define.
+            Type::Custom(name) => format!("crate::sql_types::{name}"),
+            Type::Option(inner) => format!("Nullable<{}>", Self::diesel_sql_type(inner)),
+            Type::Vector(inner) => format!("Array<{}>", Self::diesel_sql_type(inner)),
+            other => other.to_string(),
+        }
+    }
+
+    // Generates a `find_by_<pk>`/`insert`/`update`/`delete`/`list` accessor
+    // module per table, bound through sqlx's query API against `&impl
+    // GenericClient` so callers aren't forced onto one connection type.
+    fn code_from_table_queries(&self, tables: &[database::Table]) -> Vec<Snippet> {
         tables
             .iter()
             .map(|table| {
                 let table_name = self.format_name(&table.name);
-                let mut snippet = Snippet::new(table_name.clone());
-                self.add_framework_macros(&mut snippet);
+                let sql_table_name = &table.name;
+                let struct_name = table_name.to_pascal_case();
+                let params_name = format!("New{struct_name}Params");
+                let pk = table.columns.iter().find(|c| c.is_primary_key);
 
-                snippet
-                    .code
-                    .push_str(&format!("pub struct {} {{\n", table_name.to_pascal_case()));
+                let mut snippet = Snippet::new(format!("{table_name}_queries"));
+                snippet.add_dependency(&table_name);
+                snippet.add_import("crate::generator::client::GenericClient");
 
-                for column in &table.columns {
-                    let mut rust_type = self.provider.type_name_from(&column.udt_name);
-                    if column.is_nullable {
-                        rust_type = Type::Option(Box::new(rust_type));
-                    }
+                let non_pk_columns: Vec<&database::Column> = table
+                    .columns
+                    .iter()
+                    .filter(|c| pk.map(|pk| pk.name != c.name).unwrap_or(true))
+                    .collect();
 
+                snippet
+                    .code
+                    .push_str(&format!("pub struct {params_name}<'a> {{\n"));
+                for column in &non_pk_columns {
+                    let rust_type = self.column_rust_type(column);
                     self.add_type_imports(&mut snippet, &rust_type);
-                    let field_name = column.name.clone();
-                    self.add_framework_attribute(&rust_type, &mut snippet);
+                    let borrowed = Self::borrow_type(&rust_type);
+                    snippet
+                        .code
+                        .push_str(&format!("    pub {}: {borrowed},\n", column.name));
+                }
+                snippet.code.push_str("}\n\n");
 
-                    let struct_field = format!("    pub {field_name}: {rust_type},\n");
-                    snippet.code.push_str(&struct_field);
+                snippet
+                    .code
+                    .push_str("pub async fn list(client: &impl GenericClient) -> Result<Vec<");
+                snippet.code.push_str(&struct_name);
+                snippet.code.push_str(">, sqlx::Error> {\n");
+                snippet.code.push_str(&format!(
+                    "    sqlx::query_as::<_, {struct_name}>(\"SELECT * FROM {sql_table_name}\")\n"
+                ));
+                snippet.code.push_str("        .fetch_all(client)\n");
+                snippet.code.push_str("        .await\n}\n\n");
+
+                let placeholders: Vec<String> =
+                    (1..=non_pk_columns.len()).map(|i| format!("${i}")).collect();
+                let columns_list = non_pk_columns
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let binds = non_pk_columns
+                    .iter()
+                    .map(|c| format!("        .bind(params.{})\n", c.name))
+                    .collect::<String>();
+
+                if let Some(pk) = pk {
+                    let pk_type = self.column_rust_type(pk);
+                    self.add_type_imports(&mut snippet, &pk_type);
+                    let pk_name = &pk.name;
+
+                    snippet.code.push_str(&format!(
+                        "pub async fn find_by_{pk_name}(client: &impl GenericClient, {pk_name}: {pk_type}) -> Result<Option<{struct_name}>, sqlx::Error> {{\n"
+                    ));
+                    snippet.code.push_str(&format!(
+                        "    sqlx::query_as::<_, {struct_name}>(\"SELECT * FROM {sql_table_name} WHERE {pk_name} = $1\")\n"
+                    ));
+                    snippet.code.push_str(&format!("        .bind({pk_name})\n"));
+                    snippet.code.push_str("        .fetch_optional(client)\n");
+                    snippet.code.push_str("        .await\n}\n\n");
+
+                    snippet.code.push_str(&format!(
+                        "pub async fn delete(client: &impl GenericClient, {pk_name}: {pk_type}) -> Result<u64, sqlx::Error> {{\n"
+                    ));
+                    snippet.code.push_str(&format!(
+                        "    sqlx::query(\"DELETE FROM {sql_table_name} WHERE {pk_name} = $1\")\n"
+                    ));
+                    snippet.code.push_str(&format!("        .bind({pk_name})\n"));
+                    snippet.code.push_str("        .execute(client)\n");
+                    snippet
+                        .code
+                        .push_str("        .await\n        .map(|result| result.rows_affected())\n}\n\n");
+                }
+
+                snippet.code.push_str(&format!(
+                    "pub async fn insert(client: &impl GenericClient, params: {params_name}<'_>) -> Result<{struct_name}, sqlx::Error> {{\n"
+                ));
+                if non_pk_columns.is_empty() {
+                    // A table whose only column is the primary key has nothing
+                    // to list in `INSERT INTO t () VALUES ()`, which Postgres
+                    // rejects; `DEFAULT VALUES` is the SQL form for that case.
+                    snippet.code.push_str(&format!(
+                        "    sqlx::query_as::<_, {struct_name}>(\"INSERT INTO {sql_table_name} DEFAULT VALUES RETURNING *\")\n"
+                    ));
+                } else {
+                    snippet.code.push_str(&format!(
+                        "    sqlx::query_as::<_, {struct_name}>(\"INSERT INTO {sql_table_name} ({columns_list}) VALUES ({}) RETURNING *\")\n",
+                        placeholders.join(", ")
+                    ));
+                }
+                snippet.code.push_str(&binds);
+                snippet.code.push_str("        .fetch_one(client)\n");
+                snippet.code.push_str("        .await\n}\n");
+
+                // A table whose only column is its primary key has nothing
+                // left to `SET`, so there's no meaningful `update` to emit —
+                // mirroring `insert`'s `DEFAULT VALUES` special case above.
+                if let (Some(pk), false) = (pk, non_pk_columns.is_empty()) {
+                    let pk_type = self.column_rust_type(pk);
+                    let pk_name = &pk.name;
+                    let where_placeholder = format!("${}", non_pk_columns.len() + 1);
+                    let set_clause = non_pk_columns
+                        .iter()
+                        .zip(&placeholders)
+                        .map(|(c, p)| format!("{} = {p}", c.name))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    snippet.code.push('\n');
+                    snippet.code.push_str(&format!(
+                        "pub async fn update(client: &impl GenericClient, {pk_name}: {pk_type}, params: {params_name}<'_>) -> Result<{struct_name}, sqlx::Error> {{\n"
+                    ));
+                    snippet.code.push_str(&format!(
+                        "    sqlx::query_as::<_, {struct_name}>(\"UPDATE {sql_table_name} SET {set_clause} WHERE {pk_name} = {where_placeholder} RETURNING *\")\n"
+                    ));
+                    snippet.code.push_str(&binds);
+                    snippet.code.push_str(&format!("        .bind({pk_name})\n"));
+                    snippet.code.push_str("        .fetch_one(client)\n");
+                    snippet.code.push_str("        .await\n}\n");
                 }
 
-                snippet.code.push('}');
                 snippet
             })
             .collect()
     }
 
-    fn add_type_imports(&self, snippet: &mut Snippet, rust_type: &Type) {
+    fn column_rust_type(&self, column: &database::Column) -> Type {
+        let rust_type = self.provider.type_name_from(&column.udt_name);
+        if column.is_nullable {
+            Type::Option(Box::new(rust_type))
+        } else {
+            rust_type
+        }
+    }
+
+    // Borrows `String`/`Vec<u8>` fields so inserting or updating a row
+    // doesn't require cloning owned data just to build the query.
+    fn borrow_type(rust_type: &Type) -> String {
         match rust_type {
-            Type::Uuid(_) => snippet.add_import("uuid::Uuid"),
-            Type::Date(_) => snippet.add_import("chrono::NaiveDate"),
-            Type::Time(_) => snippet.add_import("chrono::NaiveTime"),
-            Type::Timestamp(_) => snippet.add_import("chrono::NaiveDateTime"),
-            Type::TimestampWithTz(_) => {
-                snippet.add_import("chrono::{DateTime, Utc}");
-            }
-            Type::Interval(_) => {
-                snippet.add_import("sqlx::postgres::types::PgInterval");
-            }
-            Type::Decimal(_) => snippet.add_import("rust_decimal::Decimal"),
-            Type::IpNetwork(_) => snippet.add_import("ipnetwork::IpNetwork"),
-            Type::Json(_) => snippet.add_import("serde_json::Value"),
-            Type::Tree(_) => snippet.add_import("postgres_types::LTree"),
-            Type::Query(_) => snippet.add_import("postgres_types::TSQuery"),
-            Type::Option(inner) => self.add_type_imports(snippet, inner),
-            Type::Vector(inner) => self.add_type_imports(snippet, inner),
-            Type::Range(inner) => {
-                snippet.add_import("sqlx::postgres::types::PgRange");
-                self.add_type_imports(snippet, inner);
+            Type::Text => "&'a str".to_string(),
+            Type::Bytes => "&'a [u8]".to_string(),
+            Type::Option(inner) => format!("Option<{}>", Self::borrow_type(inner)),
+            other => other.to_string(),
+        }
+    }
+
+    fn add_type_imports(&self, snippet: &mut Snippet, rust_type: &Type) {
+        if self.options.framework == Framework::Diesel {
+            snippet.add_import("diesel::prelude::*");
+        } else {
+            for import in self
+                .provider
+                .imports_for(rust_type, self.options.framework)
+            {
+                snippet.add_import(&import);
             }
-            Type::Money(_) => snippet.add_import("sqlx::postgres::types::PgMoney"),
-            Type::Custom(name) => {
-                if name.starts_with("postgis::") {
-                    snippet.add_import("postgis");
-                } else if name == "Oid" {
-                    snippet.add_import("sqlx::postgres::types::Oid");
-                } else if !name.contains("::") {
-                    snippet.add_dependency(name);
-                }
+        }
+        self.add_type_dependency(snippet, rust_type);
+    }
+
+    // `imports_for` only knows about external crate paths; a `Custom` type
+    // with no `::` in it instead names another struct/enum this generator
+    // is producing, so it becomes a `use super::<Name>` rather than an import.
+    fn add_type_dependency(&self, snippet: &mut Snippet, rust_type: &Type) {
+        match rust_type {
+            Type::Option(inner) | Type::Vector(inner) | Type::Range(inner) => {
+                self.add_type_dependency(snippet, inner)
             }
+            Type::Custom(name) if !name.contains("::") => snippet.add_dependency(name),
             _ => {}
         }
     }
 
-    fn add_framework_macros(&self, snippet: &mut Snippet) {
+    fn add_framework_macros(&self, snippet: &mut Snippet, table_name: &str) {
         // Add framework-specific derives and imports
         match self.options.framework {
             Framework::None => {
@@ -195,6 +507,21 @@ impl Generator {
                     .code
                     .push_str("#[derive(Debug, Clone, sqlx::FromRow)]\n");
             }
+            Framework::Postgres => {
+                snippet.add_import("postgres_types::{ToSql, FromSql}");
+                snippet
+                    .code
+                    .push_str("#[derive(Debug, Clone, ToSql, FromSql)]\n");
+            }
+            Framework::Diesel => {
+                snippet.add_import("diesel::prelude::*");
+                snippet
+                    .code
+                    .push_str("#[derive(Debug, Clone, Queryable, Selectable, Insertable)]\n");
+                snippet
+                    .code
+                    .push_str(&format!("#[diesel(table_name = {table_name})]\n"));
+            }
         }
     }
 
@@ -262,3 +589,355 @@ impl Snippet {
         self.code = final_code;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{Enum, EnumValue};
+    use async_trait::async_trait;
+
+    // An `InfoProvider` stand-in for exercising `Generator`'s string-generation
+    // methods without a real connection. Its `get_schema` returns a single
+    // `users` table (an `id` primary key plus a `name` column) for tests that
+    // need to drive the whole `generate_code`/`generate_ddl` pipeline;
+    // `supports_generic_queries` reports `true` so `Options::queries`'
+    // remaining guard (the chosen `Framework`) can be tested in isolation.
+    struct MockProvider;
+
+    #[async_trait]
+    impl InfoProvider for MockProvider {
+        async fn get_schema(&self) -> Result<database::Schema, Error> {
+            Ok(database::Schema {
+                tables: vec![database::Table {
+                    name: "users".to_string(),
+                    columns: vec![
+                        database::Column {
+                            name: "id".to_string(),
+                            udt_name: "int4".to_string(),
+                            is_nullable: false,
+                            is_primary_key: true,
+                        },
+                        database::Column {
+                            name: "name".to_string(),
+                            udt_name: "text".to_string(),
+                            is_nullable: false,
+                            is_primary_key: false,
+                        },
+                    ],
+                }],
+                ..database::Schema::default()
+            })
+        }
+
+        fn type_name_from(&self, type_name: &str) -> Type {
+            match type_name {
+                "int4" => Type::I32,
+                "text" => Type::Text,
+                other => Type::Custom(other.to_pascal_case()),
+            }
+        }
+
+        fn imports_for(&self, _rust_type: &Type, _framework: Framework) -> Vec<String> {
+            vec![]
+        }
+
+        fn supports_generic_queries(&self) -> bool {
+            true
+        }
+    }
+
+    // A minimal single-threaded executor: every future driven through the
+    // tests below (`MockProvider::get_schema`) resolves on its first poll,
+    // so there's no need to pull in an async runtime crate just to await it.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::pin::Pin;
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn noop(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        let raw_waker = RawWaker::new(std::ptr::null(), &VTABLE);
+        let waker = unsafe { Waker::from_raw(raw_waker) };
+        let mut cx = Context::from_waker(&waker);
+        let mut future = future;
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+        loop {
+            if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+                return value;
+            }
+        }
+    }
+
+    fn generator(options: Options) -> Generator {
+        Generator::new(options, Box::new(MockProvider))
+    }
+
+    fn framework_options(framework: Framework) -> Options {
+        Options {
+            framework,
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn postgres_framework_emits_postgres_types_derive_for_enums() {
+        let generator = generator(framework_options(Framework::Postgres));
+        let enums = vec![Enum {
+            name: "status".to_string(),
+            values: vec![EnumValue {
+                name: "active".to_string(),
+            }],
+        }];
+
+        let snippets = generator.code_from_enums(&enums);
+
+        assert_eq!(snippets.len(), 1);
+        let snippet = &snippets[0];
+        assert!(snippet.code.contains("ToSql, FromSql"));
+        assert!(snippet.code.contains("#[postgres(name = \"status\")]"));
+        assert!(snippet.code.contains("#[postgres(name = \"active\")]"));
+        assert!(snippet.imports.contains("postgres_types::{ToSql, FromSql}"));
+    }
+
+    #[test]
+    fn postgres_framework_emits_postgres_types_derive_for_composites() {
+        let generator = generator(framework_options(Framework::Postgres));
+        let composites = vec![database::CompositeType {
+            name: "address".to_string(),
+            attributes: vec![database::Attribute {
+                name: "city".to_string(),
+                data_type: "text".to_string(),
+            }],
+        }];
+
+        let snippets = generator.code_from_composites(&composites);
+
+        assert_eq!(snippets.len(), 1);
+        let snippet = &snippets[0];
+        assert!(snippet.code.contains("ToSql, FromSql"));
+        assert!(snippet.code.contains("#[postgres(name = \"address\")]"));
+    }
+
+    #[test]
+    fn postgres_framework_emits_postgres_types_derive_for_tables() {
+        let generator = generator(framework_options(Framework::Postgres));
+        let tables = vec![database::Table {
+            name: "users".to_string(),
+            columns: vec![database::Column {
+                name: "id".to_string(),
+                udt_name: "int4".to_string(),
+                is_nullable: false,
+                is_primary_key: true,
+            }],
+        }];
+
+        let snippets = generator.code_from_tables(&tables);
+
+        assert_eq!(snippets.len(), 1);
+        let snippet = &snippets[0];
+        assert!(snippet.code.contains("#[derive(Debug, Clone, ToSql, FromSql)]"));
+        assert!(snippet.imports.contains("postgres_types::{ToSql, FromSql}"));
+    }
+
+    fn singularized_users_table() -> database::Table {
+        database::Table {
+            name: "users".to_string(),
+            columns: vec![
+                database::Column {
+                    name: "id".to_string(),
+                    udt_name: "int4".to_string(),
+                    is_nullable: false,
+                    is_primary_key: true,
+                },
+                database::Column {
+                    name: "name".to_string(),
+                    udt_name: "text".to_string(),
+                    is_nullable: false,
+                    is_primary_key: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn table_queries_target_the_real_table_name_even_when_singular() {
+        let options = Options {
+            framework: Framework::Sqlx,
+            singular: true,
+            queries: true,
+            ..Options::default()
+        };
+        let generator = generator(options);
+        let tables = vec![singularized_users_table()];
+
+        let snippets = generator.code_from_table_queries(&tables);
+
+        assert_eq!(snippets.len(), 1);
+        let code = &snippets[0].code;
+        assert!(code.contains("FROM users"));
+        assert!(!code.contains("FROM user\""));
+        assert!(code.contains("INTO users"));
+        assert!(code.contains("UPDATE users"));
+        assert!(code.contains("DELETE FROM users"));
+    }
+
+    #[test]
+    fn pk_only_table_gets_default_values_insert_and_no_update() {
+        let generator = generator(framework_options(Framework::Sqlx));
+        let tables = vec![database::Table {
+            name: "tags".to_string(),
+            columns: vec![database::Column {
+                name: "id".to_string(),
+                udt_name: "int4".to_string(),
+                is_nullable: false,
+                is_primary_key: true,
+            }],
+        }];
+
+        let snippets = generator.code_from_table_queries(&tables);
+
+        assert_eq!(snippets.len(), 1);
+        let code = &snippets[0].code;
+        assert!(code.contains("INSERT INTO tags DEFAULT VALUES RETURNING *"));
+        assert!(!code.contains("pub async fn update"));
+    }
+
+    #[test]
+    fn table_with_non_pk_columns_still_gets_update() {
+        let generator = generator(framework_options(Framework::Sqlx));
+        let tables = vec![singularized_users_table()];
+
+        let snippets = generator.code_from_table_queries(&tables);
+
+        assert!(snippets[0].code.contains("pub async fn update"));
+    }
+
+    #[test]
+    fn queries_option_requires_supports_generic_queries() {
+        struct UnsupportedMockProvider;
+
+        #[async_trait]
+        impl InfoProvider for UnsupportedMockProvider {
+            async fn get_schema(&self) -> Result<database::Schema, Error> {
+                Ok(database::Schema::default())
+            }
+
+            fn type_name_from(&self, _type_name: &str) -> Type {
+                Type::Text
+            }
+
+            fn imports_for(&self, _rust_type: &Type, _framework: Framework) -> Vec<String> {
+                vec![]
+            }
+        }
+
+        let options = Options {
+            framework: Framework::Sqlx,
+            queries: true,
+            ..Options::default()
+        };
+        let generator = Generator::new(options, Box::new(UnsupportedMockProvider));
+
+        assert!(block_on(generator.generate_code()).is_err());
+    }
+
+    #[test]
+    fn queries_option_requires_sqlx_framework() {
+        let options = Options {
+            framework: Framework::Postgres,
+            queries: true,
+            ..Options::default()
+        };
+        let generator = generator(options);
+
+        assert!(block_on(generator.generate_code()).is_err());
+    }
+
+    #[test]
+    fn queries_option_succeeds_under_sqlx_framework() {
+        let options = Options {
+            framework: Framework::Sqlx,
+            queries: true,
+            ..Options::default()
+        };
+        let generator = generator(options);
+
+        let snippets = block_on(generator.generate_code()).expect("sqlx + queries is supported");
+        assert!(snippets.iter().any(|s| s.id == "users_queries"));
+    }
+
+    #[test]
+    fn diesel_table_schema_maps_scalar_columns() {
+        let generator = generator(framework_options(Framework::Diesel));
+        let table = singularized_users_table();
+
+        let snippets = generator.code_from_tables(&[table]);
+
+        let schema = snippets
+            .iter()
+            .find(|s| s.id == "users_schema")
+            .expect("Diesel emits a table! snippet alongside the struct");
+        assert!(schema.code.contains("table! {"));
+        assert!(schema.code.contains("id -> Integer,"));
+        assert!(schema.code.contains("name -> Text,"));
+        assert!(!schema.code.contains("#[sql_name"));
+    }
+
+    #[test]
+    fn diesel_table_schema_pins_sql_name_when_singularized() {
+        let options = Options {
+            framework: Framework::Diesel,
+            singular: true,
+            ..Options::default()
+        };
+        let generator = generator(options);
+        let table = singularized_users_table();
+
+        let snippets = generator.code_from_tables(&[table]);
+
+        let schema = snippets
+            .iter()
+            .find(|s| s.id == "user_schema")
+            .expect("the schema snippet is keyed off the formatted (singular) name");
+        assert!(schema.code.contains("#[sql_name = \"users\"]"));
+        assert!(schema.code.contains("    user (id) {"));
+    }
+
+    #[test]
+    fn diesel_table_schema_flags_custom_columns_needing_a_sql_type_marker() {
+        let generator = generator(framework_options(Framework::Diesel));
+        let table = database::Table {
+            name: "accounts".to_string(),
+            columns: vec![
+                database::Column {
+                    name: "id".to_string(),
+                    udt_name: "int4".to_string(),
+                    is_nullable: false,
+                    is_primary_key: true,
+                },
+                database::Column {
+                    name: "status".to_string(),
+                    udt_name: "account_status".to_string(),
+                    is_nullable: false,
+                    is_primary_key: false,
+                },
+            ],
+        };
+
+        let snippets = generator.code_from_tables(&[table]);
+
+        let schema = snippets
+            .iter()
+            .find(|s| s.id == "accounts_schema")
+            .unwrap();
+        assert!(schema
+            .code
+            .contains("status -> crate::sql_types::AccountStatus,"));
+        assert!(schema
+            .code
+            .contains("crate::sql_types::{AccountStatus}"));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::database::{CompositeType, Enum, Schema, Table};
+use std::collections::{HashSet, VecDeque};
+
+/// A single `CREATE TYPE`/`CREATE TABLE` statement reconstructed from the
+/// discovered schema, along with the names of the other statements it
+/// references so the caller can emit them in dependency order.
+pub struct DdlStatement {
+    pub id: String,
+    pub sql: String,
+    pub dependencies: HashSet<String>,
+}
+
+/// Builds the full set of `CREATE` statements needed to recreate `schema`,
+/// ordered so that a type is always emitted after everything it depends on.
+pub fn ddl_from_schema(schema: &Schema) -> Vec<DdlStatement> {
+    let mut statements: Vec<DdlStatement> = vec![];
+    statements.extend(schema.enumerations.iter().map(ddl_from_enum));
+    statements.extend(
+        schema
+            .composite_types
+            .iter()
+            .map(|composite| ddl_from_composite(composite, schema)),
+    );
+    statements.extend(schema.tables.iter().map(|table| ddl_from_table(table, schema)));
+
+    topological_sort(statements)
+}
+
+fn ddl_from_enum(e: &Enum) -> DdlStatement {
+    let values = e
+        .values
+        .iter()
+        .map(|v| format!("'{}'", v.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    DdlStatement {
+        id: e.name.clone(),
+        sql: format!("CREATE TYPE {} AS ENUM ({values});", e.name),
+        dependencies: HashSet::new(),
+    }
+}
+
+fn ddl_from_composite(composite: &CompositeType, schema: &Schema) -> DdlStatement {
+    let mut dependencies = HashSet::new();
+    let attributes = composite
+        .attributes
+        .iter()
+        .map(|attr| {
+            if let Some(dep) = named_type_in_schema(&attr.data_type, schema) {
+                dependencies.insert(dep);
+            }
+            format!("    {} {}", attr.name, attr.data_type)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    DdlStatement {
+        id: composite.name.clone(),
+        sql: format!("CREATE TYPE {} AS (\n{attributes}\n);", composite.name),
+        dependencies,
+    }
+}
+
+fn ddl_from_table(table: &Table, schema: &Schema) -> DdlStatement {
+    let mut dependencies = HashSet::new();
+    let mut lines: Vec<String> = table
+        .columns
+        .iter()
+        .map(|column| {
+            let element_type = column.udt_name.trim_start_matches('_');
+            if let Some(dep) = named_type_in_schema(element_type, schema) {
+                dependencies.insert(dep);
+            }
+            let array_suffix = if column.udt_name.starts_with('_') { "[]" } else { "" };
+            let nullability = if column.is_nullable { "" } else { " NOT NULL" };
+            format!("    {} {element_type}{array_suffix}{nullability}", column.name)
+        })
+        .collect();
+
+    let primary_key: Vec<&str> = table
+        .columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| c.name.as_str())
+        .collect();
+    if !primary_key.is_empty() {
+        lines.push(format!("    PRIMARY KEY ({})", primary_key.join(", ")));
+    }
+
+    DdlStatement {
+        id: table.name.clone(),
+        sql: format!("CREATE TABLE {} (\n{}\n);", table.name, lines.join(",\n")),
+        dependencies,
+    }
+}
+
+fn named_type_in_schema(type_name: &str, schema: &Schema) -> Option<String> {
+    schema
+        .enumerations
+        .iter()
+        .map(|e| &e.name)
+        .chain(schema.composite_types.iter().map(|c| &c.name))
+        .find(|name| name.as_str() == type_name)
+        .cloned()
+}
+
+// Kahn's algorithm: statements with no unmet dependencies are emitted first,
+// then whatever they unblock, and so on, so dependencies always precede
+// the `CREATE` statements that reference them.
+fn topological_sort(statements: Vec<DdlStatement>) -> Vec<DdlStatement> {
+    let mut remaining = statements;
+    let mut emitted_ids: HashSet<String> = HashSet::new();
+    let mut ordered = VecDeque::new();
+
+    while !remaining.is_empty() {
+        let (ready, not_ready): (Vec<_>, Vec<_>) = remaining
+            .into_iter()
+            .partition(|s| s.dependencies.iter().all(|d| emitted_ids.contains(d)));
+
+        // A cycle (or an unresolved dependency, e.g. on a type outside the
+        // schema) would otherwise loop forever; fall back to emitting
+        // whatever is left in its original order.
+        if ready.is_empty() {
+            ordered.extend(not_ready);
+            break;
+        }
+
+        for statement in &ready {
+            emitted_ids.insert(statement.id.clone());
+        }
+        ordered.extend(ready);
+        remaining = not_ready;
+    }
+
+    ordered.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{Attribute, Column, EnumValue};
+
+    fn enum_named(name: &str) -> Enum {
+        Enum {
+            name: name.to_string(),
+            values: vec![EnumValue {
+                name: "active".to_string(),
+            }],
+        }
+    }
+
+    fn table_named(name: &str, columns: Vec<Column>) -> Table {
+        Table {
+            name: name.to_string(),
+            columns,
+        }
+    }
+
+    fn column(name: &str, udt_name: &str, is_primary_key: bool) -> Column {
+        Column {
+            name: name.to_string(),
+            udt_name: udt_name.to_string(),
+            is_nullable: false,
+            is_primary_key,
+        }
+    }
+
+    #[test]
+    fn orders_enum_composite_and_table_by_dependency() {
+        let schema = Schema {
+            enumerations: vec![enum_named("status")],
+            composite_types: vec![CompositeType {
+                name: "address".to_string(),
+                attributes: vec![Attribute {
+                    name: "status".to_string(),
+                    data_type: "status".to_string(),
+                }],
+            }],
+            tables: vec![table_named(
+                "users",
+                vec![
+                    column("id", "int4", true),
+                    column("home", "address", false),
+                ],
+            )],
+        };
+
+        let statements = ddl_from_schema(&schema);
+        let position = |id: &str| statements.iter().position(|s| s.id == id).unwrap();
+
+        assert_eq!(statements.len(), 3);
+        assert!(position("status") < position("address"));
+        assert!(position("address") < position("users"));
+    }
+
+    #[test]
+    fn falls_back_to_input_order_on_a_cycle() {
+        let a = DdlStatement {
+            id: "a".to_string(),
+            sql: "CREATE TYPE a AS (b b);".to_string(),
+            dependencies: HashSet::from(["b".to_string()]),
+        };
+        let b = DdlStatement {
+            id: "b".to_string(),
+            sql: "CREATE TYPE b AS (a a);".to_string(),
+            dependencies: HashSet::from(["a".to_string()]),
+        };
+
+        let ordered = topological_sort(vec![a, b]);
+
+        assert_eq!(
+            ordered.into_iter().map(|s| s.id).collect::<Vec<_>>(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn table_ddl_includes_primary_key_clause() {
+        let schema = Schema::default();
+        let table = table_named(
+            "users",
+            vec![column("id", "int4", true), column("name", "text", false)],
+        );
+
+        let statement = ddl_from_table(&table, &schema);
+
+        assert!(statement.sql.contains("PRIMARY KEY (id)"));
+        assert!(statement.dependencies.is_empty());
+    }
+}
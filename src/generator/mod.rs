@@ -0,0 +1,9 @@
+pub mod client;
+pub mod code;
+pub mod ddl;
+pub mod runner;
+
+pub use client::GenericClient;
+pub use code::{Generator, Options, Snippet};
+pub use ddl::DdlStatement;
+pub use runner::Framework;
@@ -0,0 +1,16 @@
+/// Selects which ORM/driver-specific derives and attributes the generator
+/// should emit alongside the plain struct/enum definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framework {
+    /// No framework integration; only `Debug`/`Clone` are derived (plus
+    /// `PartialEq`/`Eq` on enums).
+    None,
+    /// Emit `sqlx::FromRow`/`sqlx::Type` derives and `#[sqlx(...)]` attributes.
+    Sqlx,
+    /// Emit `postgres-types` `ToSql`/`FromSql` derives and `#[postgres(...)]` attributes
+    /// for use with `tokio-postgres`/`rust-postgres` directly.
+    Postgres,
+    /// Emit a `diesel::table!` schema block plus `Queryable`/`Selectable`/`Insertable`
+    /// derives and `#[diesel(table_name = ...)]` attributes.
+    Diesel,
+}
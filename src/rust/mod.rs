@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// A Rust-side representation of a database column or attribute's type,
+/// independent of which SQL backend it came from. Apart from `Custom`, which
+/// holds the Rust name of the type to reference, variants that wrap a
+/// `String` keep the backend-reported type name (e.g. the Postgres
+/// `udt_name` or the MySQL column type) so that, should it ever be needed,
+/// the type can be traced back to the SQL it was derived from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Bool,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    Text,
+    Bytes,
+    Uuid(String),
+    Date(String),
+    Time(String),
+    Timestamp(String),
+    TimestampWithTz(String),
+    Interval(String),
+    Decimal(String),
+    IpNetwork(String),
+    Json(String),
+    Tree(String),
+    Query(String),
+    Money(String),
+    Oid(String),
+    Custom(String),
+    Option(Box<Type>),
+    Vector(Box<Type>),
+    Range(Box<Type>),
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Bool => write!(f, "bool"),
+            Type::I16 => write!(f, "i16"),
+            Type::I32 => write!(f, "i32"),
+            Type::I64 => write!(f, "i64"),
+            Type::F32 => write!(f, "f32"),
+            Type::F64 => write!(f, "f64"),
+            Type::Text => write!(f, "String"),
+            Type::Bytes => write!(f, "Vec<u8>"),
+            Type::Uuid(_) => write!(f, "Uuid"),
+            Type::Date(_) => write!(f, "NaiveDate"),
+            Type::Time(_) => write!(f, "NaiveTime"),
+            Type::Timestamp(_) => write!(f, "NaiveDateTime"),
+            Type::TimestampWithTz(_) => write!(f, "DateTime<Utc>"),
+            Type::Interval(_) => write!(f, "PgInterval"),
+            Type::Decimal(_) => write!(f, "Decimal"),
+            Type::IpNetwork(_) => write!(f, "IpNetwork"),
+            Type::Json(_) => write!(f, "Value"),
+            Type::Tree(_) => write!(f, "LTree"),
+            Type::Query(_) => write!(f, "TSQuery"),
+            Type::Money(_) => write!(f, "PgMoney"),
+            Type::Oid(_) => write!(f, "Oid"),
+            Type::Custom(name) => write!(f, "{name}"),
+            Type::Option(inner) => write!(f, "Option<{inner}>"),
+            Type::Vector(inner) => write!(f, "Vec<{inner}>"),
+            Type::Range(inner) => write!(f, "PgRange<{inner}>"),
+        }
+    }
+}